@@ -0,0 +1,2 @@
+pub mod raycast_2d;
+pub mod raycast_3d;
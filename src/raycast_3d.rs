@@ -0,0 +1,763 @@
+use std::collections::{HashSet, VecDeque};
+
+use glam::{IVec3, Vec3};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingVolume3 {
+    pub size: (i32, i32, i32),
+}
+
+impl BoundingVolume3 {
+    pub fn traverse_ray(&self, ray: Ray3) -> VoxelRay3Iterator {
+        VoxelRay3Iterator::new(self.clone(), ray)
+    }
+
+    // Sweeps a sized box (not a point) along sweep.direction, yielding every voxel the moving
+    // box overlaps, each tagged with the normal of the face it entered through.
+    pub fn traverse_aabb(&self, sweep: AabbSweep3) -> VoxelAabb3Iterator {
+        VoxelAabb3Iterator::new(self.clone(), sweep)
+    }
+
+    // Same DDA as traverse_ray, but stops at the first voxel solid() accepts. A ray starting
+    // inside a solid voxel returns that voxel with distance 0.0 and normal None.
+    pub fn cast_ray<F: FnMut((i32, i32, i32)) -> bool>(
+        &self,
+        ray: Ray3,
+        mut solid: F,
+    ) -> Option<Ray3hit> {
+        let mut iter = VoxelRay3Iterator::new(self.clone(), ray);
+        while let Some(hit) = iter.next() {
+            if solid(hit.voxel) {
+                return Some(hit);
+            }
+        }
+        None
+    }
+
+    // Branchless slab-method ray/AABB test, robust to axis-aligned and corner-grazing rays.
+    // Returns the near/far distances where ray crosses this volume's bounds.
+    pub fn intersect_ray(&self, ray: Ray3) -> Option<(f32, f32)> {
+        let origin = Vec3::from(ray.origin);
+        let dir = Vec3::from(ray.direction).normalize();
+        let inv = Vec3::new(1.0 / dir.x, 1.0 / dir.y, 1.0 / dir.z);
+
+        let min = Vec3::ZERO;
+        let max = IVec3::from(self.size).as_vec3();
+
+        let t1 = (min - origin) * inv;
+        let t2 = (max - origin) * inv;
+
+        let t_near = t1.min(t2).max_element();
+        let t_far = t1.max(t2).min_element();
+
+        if t_far >= t_near.max(0.0) && t_near <= ray.length {
+            Some((t_near, t_far))
+        } else {
+            None
+        }
+    }
+
+    // Traverses ray, recursing into cells resolution_of returns Some(r) for by remapping the
+    // ray into that cell's local [0, r) sub-grid. Cells it returns None for are leaves.
+    pub fn traverse_ray_recursive<F: FnMut((i32, i32, i32)) -> Option<i32>>(
+        &self,
+        ray: Ray3,
+        resolution_of: F,
+    ) -> VoxelRay3RecursiveIterator<F> {
+        VoxelRay3RecursiveIterator::new(self.clone(), ray, resolution_of)
+    }
+
+    #[inline(always)]
+    pub(crate) fn contains_point(&self, point: IVec3) -> bool {
+        point.cmpge(IVec3::ZERO).all() && point.cmplt(self.size.into()).all()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ray3 {
+    pub origin: (f32, f32, f32),
+    pub direction: (f32, f32, f32),
+    pub length: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ray3hit {
+    pub distance: f32,
+    pub voxel: (i32, i32, i32),
+    pub normal: Option<(i32, i32, i32)>,
+    // The t at which the ray leaves this voxel (the next step's distance) and the exit face
+    // normal. None where the traversal doesn't track a well-defined exit, e.g. traverse_aabb.
+    pub exit_distance: Option<f32>,
+    pub exit_normal: Option<(i32, i32, i32)>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VoxelRay3Iterator {
+    volume: BoundingVolume3,
+    max_d: f32,
+    i: IVec3,
+    step: IVec3,
+    delta: Vec3,
+    dist: Vec3,
+    t_max: Vec3,
+    t: f32,
+    norm: Option<IVec3>,
+    done: bool,
+}
+
+// Based on https://github.com/fenomas/fast-voxel-raycast/blob/master/index.js
+impl VoxelRay3Iterator {
+    pub fn new(volume: BoundingVolume3, ray: Ray3) -> Self {
+        let mut p = Vec3::from(ray.origin);
+
+        // Normalize direction vector
+        let d = Vec3::from(ray.direction).normalize();
+
+        // How long we have traveled thus far (modified by initial 'jump to volume bounds').
+        let mut t = 0.0;
+
+        // If the point it outside the chunk, AABB test to 'jump ahead'.
+        if !volume.contains_point(p.floor().as_ivec3()) {
+            // First AABB test the chunk bounds
+            let hit = volume.intersect_ray(ray);
+
+            // Chunk AABB test failed, no way we could intersect a voxel.
+            if hit.is_none() {
+                return Self {
+                    done: true,
+                    ..Default::default()
+                };
+            }
+
+            // Jump straight to the exact entry point instead of backing off a heuristic amount.
+            let (t_near, _t_far) = hit.unwrap();
+            t = t_near;
+            p += d * t_near;
+        }
+
+        // Max distance we can travel. This is either the ray length, or the current `t` plus the
+        // corner to corner length of the voxel volume plus 2.0 of slack.
+        let max_d = f32::min(
+            ray.length,
+            t + IVec3::from(volume.size).as_vec3().length() + 2.0,
+        );
+
+        // The starting voxel for the raycast.
+        let i = p.floor().as_ivec3();
+
+        // The directionVec we are stepping for each component.
+        let step = d.signum().as_ivec3();
+
+        // Just abs(Vec3::ONE / d) but acounts for zeros in the distance vector.
+        let delta = (Vec3::new(
+            if d.x.abs() < f32::EPSILON {
+                f32::INFINITY
+            } else {
+                1.0 / d.x
+            },
+            if d.y.abs() < f32::EPSILON {
+                f32::INFINITY
+            } else {
+                1.0 / d.y
+            },
+            if d.z.abs() < f32::EPSILON {
+                f32::INFINITY
+            } else {
+                1.0 / d.z
+            },
+        ))
+        .abs();
+
+        let dist = Vec3::new(
+            if step.x > 0 {
+                i.x as f32 + 1.0 - p.x
+            } else {
+                p.x - i.x as f32
+            },
+            if step.y > 0 {
+                i.y as f32 + 1.0 - p.y
+            } else {
+                p.y - i.y as f32
+            },
+            if step.z > 0 {
+                i.z as f32 + 1.0 - p.z
+            } else {
+                p.z - i.z as f32
+            },
+        );
+
+        // The nearest voxel boundary, as an absolute distance from the ray origin (`t` may
+        // already be non-zero if we jumped ahead to the volume bounds above).
+        let t_max = Vec3::new(
+            if delta.x < f32::INFINITY {
+                t + delta.x * dist.x
+            } else {
+                f32::INFINITY
+            },
+            if delta.y < f32::INFINITY {
+                t + delta.y * dist.y
+            } else {
+                f32::INFINITY
+            },
+            if delta.z < f32::INFINITY {
+                t + delta.z * dist.z
+            } else {
+                f32::INFINITY
+            },
+        );
+
+        Self {
+            volume,
+            max_d,
+            i,
+            step,
+            delta,
+            dist,
+            t_max,
+            t,
+            norm: None,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for VoxelRay3Iterator {
+    type Item = Ray3hit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        while self.t <= self.max_d {
+            // The next voxel boundary the ray will cross is this cell's exit point.
+            let exit_distance = self.t_max.x.min(self.t_max.y).min(self.t_max.z);
+            let exit_normal = if self.t_max.x < self.t_max.y && self.t_max.x < self.t_max.z {
+                IVec3::new(self.step.x, 0, 0)
+            } else if self.t_max.y < self.t_max.z {
+                IVec3::new(0, self.step.y, 0)
+            } else {
+                IVec3::new(0, 0, self.step.z)
+            };
+
+            // Test if the current traverse is within the volume.
+            let mut hit = None;
+            if self.volume.contains_point(self.i) {
+                hit = Some(Ray3hit {
+                    distance: self.t,
+                    voxel: self.i.into(),
+                    normal: self.norm.map(|n| n.into()),
+                    exit_distance: Some(exit_distance),
+                    exit_normal: Some(exit_normal.into()),
+                });
+            }
+
+            // Select the smallest t_max
+            if self.t_max.x < self.t_max.y && self.t_max.x < self.t_max.z {
+                self.i.x += self.step.x;
+                self.t = self.t_max.x;
+                self.t_max.x += self.delta.x;
+                self.norm = Some(IVec3::new(-self.step.x, 0, 0));
+            } else if self.t_max.y < self.t_max.z {
+                self.i.y += self.step.y;
+                self.t = self.t_max.y;
+                self.t_max.y += self.delta.y;
+                self.norm = Some(IVec3::new(0, -self.step.y, 0));
+            } else {
+                self.i.z += self.step.z;
+                self.t = self.t_max.z;
+                self.t_max.z += self.delta.z;
+                self.norm = Some(IVec3::new(0, 0, -self.step.z));
+            }
+
+            if hit.is_some() {
+                return hit;
+            }
+        }
+
+        self.done = true;
+        return None;
+    }
+}
+
+// A sized box swept from origin along direction for length, for BoundingVolume3::traverse_aabb.
+#[derive(Debug, Clone, Copy)]
+pub struct AabbSweep3 {
+    pub half_extents: (f32, f32, f32),
+    pub origin: (f32, f32, f32),
+    pub direction: (f32, f32, f32),
+    pub length: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct VoxelAabb3Iterator {
+    volume: BoundingVolume3,
+    half_extents: Vec3,
+    pos: Vec3,
+    dir: Vec3,
+    step: IVec3,
+    max_d: f32,
+    t: f32,
+    min_cell: IVec3,
+    max_cell: IVec3,
+    pending: VecDeque<Ray3hit>,
+    seen: HashSet<(i32, i32, i32)>,
+    done: bool,
+}
+
+impl VoxelAabb3Iterator {
+    pub fn new(volume: BoundingVolume3, sweep: AabbSweep3) -> Self {
+        let half_extents = Vec3::from(sweep.half_extents);
+        let pos = Vec3::from(sweep.origin);
+        let dir = Vec3::from(sweep.direction).normalize();
+        let step = dir.signum().as_ivec3();
+
+        let min_cell = (pos - half_extents).floor().as_ivec3();
+        let max_cell = (pos + half_extents).ceil().as_ivec3() - IVec3::ONE;
+
+        let mut iter = Self {
+            volume,
+            half_extents,
+            pos,
+            dir,
+            step,
+            max_d: sweep.length,
+            t: 0.0,
+            min_cell,
+            max_cell,
+            pending: VecDeque::new(),
+            seen: HashSet::new(),
+            done: false,
+        };
+
+        // Seed the pending queue with every voxel overlapped by the box at its start position.
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                for z in min_cell.z..=max_cell.z {
+                    iter.emit(IVec3::new(x, y, z), None);
+                }
+            }
+        }
+
+        iter
+    }
+
+    fn emit(&mut self, voxel: IVec3, normal: Option<IVec3>) {
+        if !self.volume.contains_point(voxel) {
+            return;
+        }
+        if !self.seen.insert(voxel.into()) {
+            return;
+        }
+        self.pending.push_back(Ray3hit {
+            distance: self.t,
+            voxel: voxel.into(),
+            normal: normal.map(|n| n.into()),
+            exit_distance: None,
+            exit_normal: None,
+        });
+    }
+
+    // Advances the box to the next axis crossing, shifting it and emitting the newly entered
+    // slab of cells along the crossed axis.
+    fn step_once(&mut self) {
+        let leading = Vec3::new(
+            if self.step.x > 0 {
+                self.pos.x + self.half_extents.x
+            } else {
+                self.pos.x - self.half_extents.x
+            },
+            if self.step.y > 0 {
+                self.pos.y + self.half_extents.y
+            } else {
+                self.pos.y - self.half_extents.y
+            },
+            if self.step.z > 0 {
+                self.pos.z + self.half_extents.z
+            } else {
+                self.pos.z - self.half_extents.z
+            },
+        );
+
+        let next_boundary = Vec3::new(
+            if self.step.x > 0 {
+                self.max_cell.x as f32 + 1.0
+            } else {
+                self.min_cell.x as f32
+            },
+            if self.step.y > 0 {
+                self.max_cell.y as f32 + 1.0
+            } else {
+                self.min_cell.y as f32
+            },
+            if self.step.z > 0 {
+                self.max_cell.z as f32 + 1.0
+            } else {
+                self.min_cell.z as f32
+            },
+        );
+
+        let t_axis = Vec3::new(
+            if self.dir.x.abs() < f32::EPSILON {
+                f32::INFINITY
+            } else {
+                (next_boundary.x - leading.x) / self.dir.x
+            },
+            if self.dir.y.abs() < f32::EPSILON {
+                f32::INFINITY
+            } else {
+                (next_boundary.y - leading.y) / self.dir.y
+            },
+            if self.dir.z.abs() < f32::EPSILON {
+                f32::INFINITY
+            } else {
+                (next_boundary.z - leading.z) / self.dir.z
+            },
+        );
+
+        let t_min = t_axis.x.min(t_axis.y).min(t_axis.z);
+        if !t_min.is_finite() || self.t + t_min > self.max_d {
+            self.done = true;
+            return;
+        }
+
+        self.t += t_min;
+        self.pos += self.dir * t_min;
+
+        // Pull the trailing bound up to the moved box so a slab spans only cells it now covers.
+        let trailing_min = (self.pos - self.half_extents).floor().as_ivec3();
+        let trailing_max = (self.pos + self.half_extents).ceil().as_ivec3() - IVec3::ONE;
+        let forward = self.step.cmpgt(IVec3::ZERO);
+        self.min_cell = IVec3::select(forward, trailing_min, self.min_cell);
+        self.max_cell = IVec3::select(forward, self.max_cell, trailing_max);
+
+        if t_axis.x <= t_min {
+            if self.step.x > 0 {
+                self.max_cell.x += 1;
+            } else {
+                self.min_cell.x -= 1;
+            }
+            let x = if self.step.x > 0 {
+                self.max_cell.x
+            } else {
+                self.min_cell.x
+            };
+            for y in self.min_cell.y..=self.max_cell.y {
+                for z in self.min_cell.z..=self.max_cell.z {
+                    self.emit(IVec3::new(x, y, z), Some(IVec3::new(-self.step.x, 0, 0)));
+                }
+            }
+        }
+        if t_axis.y <= t_min {
+            if self.step.y > 0 {
+                self.max_cell.y += 1;
+            } else {
+                self.min_cell.y -= 1;
+            }
+            let y = if self.step.y > 0 {
+                self.max_cell.y
+            } else {
+                self.min_cell.y
+            };
+            for x in self.min_cell.x..=self.max_cell.x {
+                for z in self.min_cell.z..=self.max_cell.z {
+                    self.emit(IVec3::new(x, y, z), Some(IVec3::new(0, -self.step.y, 0)));
+                }
+            }
+        }
+        if t_axis.z <= t_min {
+            if self.step.z > 0 {
+                self.max_cell.z += 1;
+            } else {
+                self.min_cell.z -= 1;
+            }
+            let z = if self.step.z > 0 {
+                self.max_cell.z
+            } else {
+                self.min_cell.z
+            };
+            for x in self.min_cell.x..=self.max_cell.x {
+                for y in self.min_cell.y..=self.max_cell.y {
+                    self.emit(IVec3::new(x, y, z), Some(IVec3::new(0, 0, -self.step.z)));
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for VoxelAabb3Iterator {
+    type Item = Ray3hit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(hit) = self.pending.pop_front() {
+                return Some(hit);
+            }
+
+            if self.done || self.t > self.max_d {
+                self.done = true;
+                return None;
+            }
+
+            self.step_once();
+        }
+    }
+}
+
+// A hit from traverse_ray_recursive. sub_voxel is Some when cell was recursed into, None
+// when it was treated as a leaf.
+#[derive(Debug, Clone, Copy)]
+pub struct RecursiveRay3hit {
+    pub distance: f32,
+    pub cell: (i32, i32, i32),
+    pub sub_voxel: Option<(i32, i32, i32)>,
+    pub normal: Option<(i32, i32, i32)>,
+    pub exit_distance: Option<f32>,
+    pub exit_normal: Option<(i32, i32, i32)>,
+}
+
+pub struct VoxelRay3RecursiveIterator<F> {
+    outer: VoxelRay3Iterator,
+    ray: Ray3,
+    resolution_of: F,
+    pending: VecDeque<RecursiveRay3hit>,
+    done: bool,
+}
+
+impl<F: FnMut((i32, i32, i32)) -> Option<i32>> VoxelRay3RecursiveIterator<F> {
+    pub fn new(volume: BoundingVolume3, ray: Ray3, resolution_of: F) -> Self {
+        Self {
+            outer: VoxelRay3Iterator::new(volume, ray),
+            ray,
+            resolution_of,
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+impl<F: FnMut((i32, i32, i32)) -> Option<i32>> Iterator for VoxelRay3RecursiveIterator<F> {
+    type Item = RecursiveRay3hit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(hit) = self.pending.pop_front() {
+                return Some(hit);
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let outer_hit = match self.outer.next() {
+                Some(hit) => hit,
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            };
+
+            let resolution = (self.resolution_of)(outer_hit.voxel);
+
+            let r = match resolution {
+                Some(r) => r,
+                None => {
+                    return Some(RecursiveRay3hit {
+                        distance: outer_hit.distance,
+                        cell: outer_hit.voxel,
+                        sub_voxel: None,
+                        normal: outer_hit.normal,
+                        exit_distance: outer_hit.exit_distance,
+                        exit_normal: outer_hit.exit_normal,
+                    });
+                }
+            };
+
+            // Remap the ray into the sub-cell's local [0, r) space: subtract the cell origin,
+            // then scale up by the sub-grid resolution.
+            let dir = Vec3::from(self.ray.direction).normalize();
+            let world_pos = Vec3::from(self.ray.origin) + dir * outer_hit.distance;
+            let cell_origin = Vec3::new(
+                outer_hit.voxel.0 as f32,
+                outer_hit.voxel.1 as f32,
+                outer_hit.voxel.2 as f32,
+            );
+            let local_pos = (world_pos - cell_origin) * r as f32;
+            let local_length = (self.ray.length - outer_hit.distance) * r as f32;
+
+            let sub_volume = BoundingVolume3 { size: (r, r, r) };
+            let sub_ray = Ray3 {
+                origin: local_pos.into(),
+                direction: self.ray.direction,
+                length: local_length,
+            };
+
+            for sub_hit in sub_volume.traverse_ray(sub_ray) {
+                self.pending.push_back(RecursiveRay3hit {
+                    distance: outer_hit.distance + sub_hit.distance / r as f32,
+                    cell: outer_hit.voxel,
+                    sub_voxel: Some(sub_hit.voxel),
+                    normal: sub_hit.normal.or(outer_hit.normal),
+                    exit_distance: sub_hit
+                        .exit_distance
+                        .map(|d| outer_hit.distance + d / r as f32)
+                        .or(outer_hit.exit_distance),
+                    exit_normal: sub_hit.exit_normal.or(outer_hit.exit_normal),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traverse_aabb_axis_aligned() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let sweep = AabbSweep3 {
+            half_extents: (0.4, 0.4, 0.4),
+            origin: (0.0, 0.0, 0.0),
+            direction: (1.0, 0.0, 0.0),
+            length: 3.0,
+        };
+        let voxels: Vec<_> = volume.traverse_aabb(sweep).map(|h| h.voxel).collect();
+        assert_eq!(voxels, vec![(0, 0, 0), (1, 0, 0), (2, 0, 0), (3, 0, 0)]);
+    }
+
+    #[test]
+    fn traverse_aabb_diagonal_dedups_corners() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let sweep = AabbSweep3 {
+            half_extents: (0.5, 0.5, 0.5),
+            origin: (0.5, 0.5, 0.5),
+            direction: (1.0, 1.0, 1.0),
+            length: 3.0,
+        };
+        let voxels: Vec<_> = volume.traverse_aabb(sweep).map(|h| h.voxel).collect();
+        let unique: HashSet<_> = voxels.iter().copied().collect();
+        assert_eq!(voxels.len(), unique.len());
+    }
+
+    #[test]
+    fn traverse_aabb_zero_length_zero_direction_sweep_yields_start_voxel_only() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let sweep = AabbSweep3 {
+            half_extents: (0.1, 0.1, 0.1),
+            origin: (1.5, 1.5, 1.5),
+            direction: (0.0, 0.0, 0.0),
+            length: 0.0,
+        };
+        let voxels: Vec<_> = volume.traverse_aabb(sweep).map(|h| h.voxel).collect();
+        assert_eq!(voxels, vec![(1, 1, 1)]);
+    }
+
+    #[test]
+    fn cast_ray_stops_at_first_solid_voxel() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let ray = Ray3 {
+            origin: (0.5, 0.5, 0.5),
+            direction: (1.0, 0.0, 0.0),
+            length: 100.0,
+        };
+        let hit = volume.cast_ray(ray, |voxel| voxel == (2, 0, 0)).unwrap();
+        assert_eq!(hit.voxel, (2, 0, 0));
+        assert_eq!(hit.distance, 1.5);
+        assert_eq!(hit.normal, Some((-1, 0, 0)));
+    }
+
+    #[test]
+    fn cast_ray_starting_in_solid_voxel_returns_zero_distance() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let ray = Ray3 {
+            origin: (0.5, 0.5, 0.5),
+            direction: (1.0, 0.0, 0.0),
+            length: 100.0,
+        };
+        let hit = volume.cast_ray(ray, |_| true).unwrap();
+        assert_eq!(hit.voxel, (0, 0, 0));
+        assert_eq!(hit.distance, 0.0);
+        assert_eq!(hit.normal, None);
+    }
+
+    #[test]
+    fn intersect_ray_axis_aligned() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let ray = Ray3 {
+            origin: (-2.0, 2.0, 2.0),
+            direction: (1.0, 0.0, 0.0),
+            length: 100.0,
+        };
+        assert_eq!(volume.intersect_ray(ray), Some((2.0, 6.0)));
+    }
+
+    #[test]
+    fn intersect_ray_diagonal_through_corner() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let ray = Ray3 {
+            origin: (-1.0, -1.0, -1.0),
+            direction: (1.0, 1.0, 1.0),
+            length: 100.0,
+        };
+        let (t_near, t_far) = volume.intersect_ray(ray).unwrap();
+        assert!((t_near - 3.0f32.sqrt()).abs() < 1e-5);
+        assert!((t_far - 5.0 * 3.0f32.sqrt()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn intersect_ray_miss() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let ray = Ray3 {
+            origin: (-1.0, 10.0, 10.0),
+            direction: (1.0, 0.0, 0.0),
+            length: 100.0,
+        };
+        assert_eq!(volume.intersect_ray(ray), None);
+    }
+
+    #[test]
+    fn traverse_ray_recursive_subdivides_only_the_chosen_cell() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let ray = Ray3 {
+            origin: (-2.0, 0.5, 0.5),
+            direction: (1.0, 0.0, 0.0),
+            length: 100.0,
+        };
+        let hits: Vec<_> = volume
+            .traverse_ray_recursive(ray, |cell| if cell == (1, 0, 0) { Some(2) } else { None })
+            .collect();
+
+        assert_eq!(hits[0].cell, (0, 0, 0));
+        assert_eq!(hits[0].sub_voxel, None);
+        assert_eq!(hits[0].distance, 2.0);
+        assert_eq!(hits[0].exit_distance, Some(3.0));
+        assert_eq!(hits[0].exit_normal, Some((1, 0, 0)));
+
+        let recursed: Vec<_> = hits.iter().filter(|h| h.cell == (1, 0, 0)).collect();
+        assert_eq!(recursed[0].distance, 3.0);
+        assert_eq!(recursed[1].distance, 3.5);
+        assert_eq!(recursed[0].exit_distance, Some(3.5));
+        assert_eq!(recursed[0].exit_normal, Some((1, 0, 0)));
+        assert_eq!(recursed[1].exit_distance, Some(4.0));
+        assert_eq!(recursed[1].exit_normal, Some((1, 0, 0)));
+
+        assert_eq!(hits.last().unwrap().cell, (3, 0, 0));
+        assert_eq!(hits.last().unwrap().distance, 5.0);
+    }
+
+    #[test]
+    fn traverse_ray_exit_distance_and_normal_point_in_direction_of_travel() {
+        let volume = BoundingVolume3 { size: (4, 4, 4) };
+        let ray = Ray3 {
+            origin: (-2.0, 0.5, 0.5),
+            direction: (1.0, 0.0, 0.0),
+            length: 100.0,
+        };
+        let hit = volume.traverse_ray(ray).next().unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.exit_distance, Some(3.0));
+        assert_eq!(hit.exit_normal, Some((1, 0, 0)));
+    }
+}
+